@@ -23,7 +23,7 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
     parse_macro_input, AttributeArgs, ItemFn, Lit, Meta, NestedMeta, Pat, PatType, ReturnType,
-    Type, Visibility,
+    Type,
 };
 
 // ----------------------------------------------------------------
@@ -39,6 +39,26 @@ use syn::{
 ///
 /// - `before` - The path to a function to be called before the target function.
 /// - `after` - The path to a function to be called after the target function.
+/// - `around` - The path to a function that receives the target arguments plus a
+///   `proceed` closure wrapping the target body; it decides whether, when, and how
+///   to invoke `proceed()`. When combined with `before`/`after`, the order is
+///   `before` → `around(proceed)` → `after`.
+/// - `after_returning` - The path to a function called with `&T` only when a
+///   `Result<T, E>` target returns `Ok(T)`.
+/// - `after_throwing` - The path to a function called with `&E` only when a
+///   `Result<T, E>` target returns `Err(E)`. Supplying `after_returning`/
+///   `after_throwing` on a non-`Result` target is a compile error.
+/// - `await_before` - Whether to `.await` the `before` advice. Defaults to
+///   `false`; set it to `true` only when the `before` path is itself an
+///   `async fn` (synchronous advice works on `async fn` targets as-is).
+/// - `await_after` - Whether to `.await` the `after` advice. Defaults to
+///   `false`; set it to `true` only when the `after` path is itself an
+///   `async fn`.
+///
+/// The macro also supports `async fn` targets directly: the generated wrapper
+/// keeps the `async` modifier and runs the target body inline (no return type)
+/// or inside an awaited, non-`move` `async` block (with a return type), so
+/// `.await` points in the body compose naturally.
 ///
 /// # Examples
 ///
@@ -149,86 +169,237 @@ pub fn Aspect(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as AttributeArgs);
     let input = parse_macro_input!(input as ItemFn);
 
+    // Surface every failure path as a spanned `compile_error!` anchored at the
+    // offending tokens instead of panicking inside the proc-macro, so tooling can
+    // report precise, recoverable diagnostics inline.
+    match expand(args, input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+fn expand(args: AttributeArgs, input: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let attrs = &input.attrs;
     let fn_name = &input.sig.ident;
     let fn_block = &input.block;
     let fn_inputs = &input.sig.inputs;
     let fn_generics = &input.sig.generics;
+    let fn_where = &input.sig.generics.where_clause;
     let fn_output = &input.sig.output;
+    let fn_asyncness = &input.sig.asyncness;
+    let is_async = fn_asyncness.is_some();
 
-    let fn_args: Vec<_> = fn_inputs
-        .iter()
-        .map(|arg| match arg {
+    // The `self` receiver is not forwarded to the advice: advice functions are
+    // free functions that observe the call arguments, so only the typed inputs
+    // are threaded through.
+    let mut fn_args = Vec::new();
+    for arg in fn_inputs.iter() {
+        match arg {
             syn::FnArg::Typed(PatType { pat, ty, .. }) => {
                 if let Pat::Ident(pat_ident) = &**pat {
                     let ident = &pat_ident.ident;
-                    match **ty {
+                    fn_args.push(match **ty {
                         Type::Reference(_) => quote! { #ident },
                         _ => quote! { &#ident },
-                    }
+                    });
                 } else {
-                    panic!("Expected an identifier pattern")
+                    return Err(syn::Error::new_spanned(
+                        pat,
+                        "expected an identifier pattern",
+                    ));
                 }
             }
-            syn::FnArg::Receiver(_) => panic!("Expected a typed pattern, not self"),
-        })
-        .collect();
+            syn::FnArg::Receiver(_) => {}
+        }
+    }
 
     let mut before_fn = None;
     let mut after_fn = None;
+    let mut around_fn = None;
+    let mut after_returning_fn = None;
+    let mut after_throwing_fn = None;
+
+    // Whether the `before`/`after` advice should itself be `.await`ed. A
+    // proc-macro cannot see whether the advice *path* resolves to an `async fn`,
+    // so awaiting is opt-in: synchronous advice (the common tracing/metrics case)
+    // works on `async fn` targets out of the box, and users pass
+    // `await_before = true`/`await_after = true` only when their advice is async.
+    let mut await_before = false;
+    let mut await_after = false;
 
     for arg in args {
         if let NestedMeta::Meta(Meta::NameValue(meta)) = arg {
-            if let (Some(ident), Lit::Str(lit_str)) = (meta.path.get_ident(), meta.lit) {
-                match ident.to_string().as_str() {
-                    "before" => {
-                        before_fn = Some(syn::parse_str::<syn::Path>(&lit_str.value()).unwrap())
+            if let Some(ident) = meta.path.get_ident() {
+                match (ident.to_string().as_str(), &meta.lit) {
+                    ("before", Lit::Str(lit_str)) => before_fn = Some(parse_advice_path(lit_str)?),
+                    ("after", Lit::Str(lit_str)) => after_fn = Some(parse_advice_path(lit_str)?),
+                    ("around", Lit::Str(lit_str)) => around_fn = Some(parse_advice_path(lit_str)?),
+                    ("after_returning", Lit::Str(lit_str)) => {
+                        after_returning_fn = Some(parse_advice_path(lit_str)?)
+                    }
+                    ("after_throwing", Lit::Str(lit_str)) => {
+                        after_throwing_fn = Some(parse_advice_path(lit_str)?)
                     }
-                    "after" => {
-                        after_fn = Some(syn::parse_str::<syn::Path>(&lit_str.value()).unwrap())
+                    ("await_before", Lit::Bool(lit_bool)) => await_before = lit_bool.value,
+                    ("await_after", Lit::Bool(lit_bool)) => await_after = lit_bool.value,
+                    (key, _) => {
+                        return Err(syn::Error::new_spanned(
+                            &meta.path,
+                            format!("unknown `Aspect` argument `{}`", key),
+                        ))
                     }
-                    _ => {}
                 }
             }
         }
     }
 
     let before_call = if let Some(before) = before_fn {
-        quote! {
-            #before(#(#fn_args),*);
+        if await_before {
+            quote! {
+                #before(#(#fn_args),*).await;
+            }
+        } else {
+            quote! {
+                #before(#(#fn_args),*);
+            }
         }
     } else {
         quote! {}
     };
 
     let after_call = if let Some(after) = after_fn {
-        quote! {
-            #after(#(#fn_args),*);
+        if await_after {
+            quote! {
+                #after(#(#fn_args),*).await;
+            }
+        } else {
+            quote! {
+                #after(#(#fn_args),*);
+            }
         }
     } else {
         quote! {}
     };
 
-    let vis = match input.vis {
-        Visibility::Public(_) => quote! { pub },
-        _ => quote! { pub(crate) },
+    // Preserve the target's original visibility verbatim so private and
+    // `pub(super)`/`pub(in ...)` methods keep their visibility instead of being
+    // promoted to `pub`/`pub(crate)`.
+    let vis = &input.vis;
+
+    // `after_returning`/`after_throwing` only make sense for a target returning
+    // `Result<T, E>`; detect it by the last path segment of the return type.
+    let result_return = match fn_output {
+        ReturnType::Type(_, ty) => is_result_type(ty),
+        ReturnType::Default => false,
+    };
+
+    if (after_returning_fn.is_some() || after_throwing_fn.is_some()) && !result_return {
+        return Err(syn::Error::new_spanned(
+            &input.sig,
+            "`after_returning`/`after_throwing` require a target returning `Result<T, E>`",
+        ));
+    }
+
+    // Success/error interception for fallible targets. `after` keeps running
+    // unconditionally afterwards (finally semantics), so the match only observes
+    // the result by reference and leaves it intact for the return.
+    let result_intercept = if after_returning_fn.is_some() || after_throwing_fn.is_some() {
+        let ok_arm = if let Some(ar) = &after_returning_fn {
+            quote! { #ar(v); }
+        } else {
+            quote! {}
+        };
+        let err_arm = if let Some(at) = &after_throwing_fn {
+            quote! { #at(e); }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            match &result {
+                Ok(v) => { #ok_arm }
+                Err(e) => { #err_arm }
+            }
+        }
+    } else {
+        quote! {}
     };
 
     let expanded = match fn_output {
         ReturnType::Default => {
+            // With `around`, the body is handed to the advice as a `proceed`
+            // closure so it decides whether and when to run it; otherwise the body
+            // runs inline between the before/after hooks. `proceed` borrows (rather
+            // than moves) the arguments so the same non-`Copy` params can also be
+            // forwarded to `around` by reference. For an `async fn` target the body
+            // may contain `.await`, which a plain closure cannot host, so `proceed`
+            // yields an `async move` block and the awaited `around` call (itself an
+            // `async fn`) supplies the result.
+            let core = if let Some(around) = &around_fn {
+                if is_async {
+                    quote! {
+                        let proceed = || async move { #fn_block };
+                        #around(#(#fn_args,)* proceed).await;
+                    }
+                } else {
+                    quote! {
+                        let proceed = || { #fn_block };
+                        #around(#(#fn_args,)* proceed);
+                    }
+                }
+            } else {
+                quote! { { #fn_block } }
+            };
+
             quote! {
-                #vis fn #fn_name #fn_generics (#fn_inputs) {
+                #(#attrs)*
+                #vis #fn_asyncness fn #fn_name #fn_generics (#fn_inputs) #fn_where {
                     #before_call
-                    #fn_block
+                    #core
                     #after_call
                 }
             }
         }
 
         ReturnType::Type(_, ty) => {
+            // A plain closure cannot host an `.await`, so async targets run the
+            // body inside an awaited `async` block; synchronous targets keep
+            // the immediately-invoked closure form. `around` captures the body as
+            // a `proceed` closure and returns whatever the advice yields; `proceed`
+            // borrows its captures so non-`Copy` params can also be forwarded to
+            // `around` by reference. For an `async fn` target `proceed` yields an
+            // `async move` block and the awaited `around` call supplies the result.
+            let invoke = if let Some(around) = &around_fn {
+                if is_async {
+                    quote! {
+                        {
+                            let proceed = || async move { #fn_block };
+                            #around(#(#fn_args,)* proceed).await
+                        }
+                    }
+                } else {
+                    quote! {
+                        {
+                            let proceed = || { #fn_block };
+                            #around(#(#fn_args,)* proceed)
+                        }
+                    }
+                }
+            } else if is_async {
+                // Awaited in place, so the body need not own the arguments: a
+                // non-`move` block leaves non-`Copy` params borrowable by the
+                // `after`/`after_returning`/`after_throwing` advice afterwards.
+                quote! { async { #fn_block }.await }
+            } else {
+                quote! { (|| { #fn_block })() }
+            };
+
             quote! {
-                #vis fn #fn_name #fn_generics (#fn_inputs) -> #ty {
+                #(#attrs)*
+                #vis #fn_asyncness fn #fn_name #fn_generics (#fn_inputs) -> #ty #fn_where {
                     #before_call
-                    let result = (|| { #fn_block })();
+                    let result = #invoke;
+                    #result_intercept
                     #after_call
                     result
                 }
@@ -236,5 +407,26 @@ pub fn Aspect(args: TokenStream, input: TokenStream) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
+}
+
+/// Parses the string literal of a `before`/`after`/`around` argument into a
+/// `syn::Path`, re-anchoring any parse error at the literal's span so the
+/// diagnostic points at the user's attribute rather than the macro internals.
+fn parse_advice_path(lit_str: &syn::LitStr) -> syn::Result<syn::Path> {
+    lit_str.parse::<syn::Path>().map_err(|_| {
+        syn::Error::new_spanned(lit_str, format!("expected a valid path, found `{}`", lit_str.value()))
+    })
+}
+
+/// Returns `true` when `ty` is a `Result`, determined by the last segment of its
+/// path (so both `Result<T, E>` and `std::result::Result<T, E>` are recognised).
+fn is_result_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "Result";
+        }
+    }
+
+    false
 }
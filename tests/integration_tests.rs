@@ -196,3 +196,259 @@ mod after {
 
     // $ cargo test --test integration_tests -- --show-output
 }
+
+// ---------------------------------------------------------------- async
+
+/// A minimal single-threaded executor so the async targets can be driven
+/// without pulling a runtime into the test tree; the futures here resolve on
+/// the first poll.
+mod executor {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    pub fn block_on<F: Future>(mut fut: F) -> F::Output {
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+        // SAFETY: `fut` lives on this stack frame and is never moved after
+        // being pinned below.
+        let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+        loop {
+            if let Poll::Ready(val) = fut.as_mut().poll(&mut cx) {
+                return val;
+            }
+        }
+    }
+}
+
+// A synchronous `before`/`after` pair instruments an `async fn` target without
+// any `await_*` flags (the default).
+#[Aspect(before = "before_fn", after = "after_fn")]
+pub async fn say_hello_async(x: i64) -> i64 {
+    println!("async::Hello, {}", x);
+
+    x
+}
+
+async fn async_before_fn(x: &i64) {
+    println!("async_before_fn::Before: {}", x);
+}
+
+async fn async_after_fn(x: &i64) {
+    println!("async_after_fn::After: {}", x);
+}
+
+// Async advice is awaited only with the explicit opt-in flags.
+#[Aspect(
+    before = "async_before_fn",
+    after = "async_after_fn",
+    await_before = true,
+    await_after = true
+)]
+pub async fn say_hello_async_advice(x: i64) -> i64 {
+    x * 2
+}
+
+#[test]
+fn test_aspect_say_hello_async() {
+    let rvt = executor::block_on(say_hello_async(42));
+
+    assert_eq!(42, rvt)
+}
+
+#[test]
+fn test_aspect_say_hello_async_advice() {
+    let rvt = executor::block_on(say_hello_async_advice(21));
+
+    assert_eq!(42, rvt)
+}
+
+fn after_name_fn(name: &String) {
+    println!("after_name_fn::After: {}", name);
+}
+
+// A non-`Copy` argument on an `async fn` target: the `after` advice borrows the
+// parameter once the awaited body has run, so the body must not move it away.
+#[Aspect(after = "after_name_fn")]
+pub async fn greet_async(name: String) -> usize {
+    name.len()
+}
+
+#[test]
+fn test_aspect_greet_async() {
+    let rvt = executor::block_on(greet_async("photowey".to_string()));
+
+    assert_eq!(8, rvt)
+}
+
+// ---------------------------------------------------------------- around
+
+fn around_fn(x: &i64, proceed: impl FnOnce() -> i64) -> i64 {
+    println!("around_fn::Before: {}", x);
+    let rvt = proceed();
+    println!("around_fn::After: {}", rvt);
+
+    rvt + 1
+}
+
+// `around` short-circuits the body entirely and supplies its own result.
+fn around_short_circuit_fn(x: &i64, _proceed: impl FnOnce() -> i64) -> i64 {
+    println!("around_short_circuit_fn: {}", x);
+
+    -1
+}
+
+async fn around_async_fn<F, Fut>(x: &i64, proceed: F) -> i64
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = i64>,
+{
+    let rvt = proceed().await;
+
+    rvt + *x
+}
+
+#[Aspect(around = "around_fn")]
+pub fn say_hello_around(x: i64) -> i64 {
+    println!("around::Hello, {}", x);
+
+    x
+}
+
+#[Aspect(around = "around_short_circuit_fn")]
+pub fn say_hello_around_short_circuit(x: i64) -> i64 {
+    println!("around::Hello:short_circuit, {}", x);
+
+    x
+}
+
+#[Aspect(around = "around_async_fn")]
+pub async fn say_hello_around_async(x: i64) -> i64 {
+    x
+}
+
+#[test]
+fn test_aspect_say_hello_around() {
+    // body returns 42, `around` adds 1.
+    assert_eq!(43, say_hello_around(42));
+}
+
+#[test]
+fn test_aspect_say_hello_around_short_circuit() {
+    assert_eq!(-1, say_hello_around_short_circuit(42));
+}
+
+#[test]
+fn test_aspect_say_hello_around_async() {
+    // body returns 42, `around` awaits `proceed()` then adds `x`.
+    let rvt = executor::block_on(say_hello_around_async(42));
+
+    assert_eq!(84, rvt)
+}
+
+fn around_str_fn(s: &String, proceed: impl FnOnce() -> usize) -> usize {
+    println!("around_str_fn::Before: {}", s);
+
+    proceed()
+}
+
+// A non-`Copy` argument with `around`: `proceed` borrows the parameter so it can
+// still be forwarded to the advice by reference in the same call.
+#[Aspect(around = "around_str_fn")]
+pub fn measure(s: String) -> usize {
+    s.len()
+}
+
+#[test]
+fn test_aspect_measure() {
+    assert_eq!(8, measure("photowey".to_string()));
+}
+
+// ---------------------------------------------------------------- self receiver
+
+pub struct Calculator {
+    base: i64,
+}
+
+impl Calculator {
+    // `&self` is excluded from the forwarded advice arguments; only `x` is.
+    #[Aspect(before = "before_fn", after = "after_fn")]
+    pub fn add(&self, x: i64) -> i64 {
+        self.base + x
+    }
+
+    // A private method keeps its visibility instead of being promoted to `pub`.
+    #[Aspect(before = "before_fn_empty", after = "after_fn_empty")]
+    fn base(&self) -> i64 {
+        self.base
+    }
+}
+
+#[test]
+fn test_aspect_self_receiver() {
+    let calc = Calculator { base: 100 };
+
+    assert_eq!(142, calc.add(42));
+    assert_eq!(100, calc.base());
+}
+
+// ---------------------------------------------------------------- result
+
+fn after_returning_fn(v: &i64) {
+    println!("after_returning_fn::Ok: {}", v);
+}
+
+fn after_throwing_fn(e: &String) {
+    println!("after_throwing_fn::Err: {}", e);
+}
+
+fn finally_fn(ok: &bool) {
+    println!("finally_fn: {}", ok);
+}
+
+// `after_returning` fires only on `Ok`, `after_throwing` only on `Err`, while
+// `after` still runs unconditionally (finally semantics).
+#[Aspect(
+    after = "finally_fn",
+    after_returning = "after_returning_fn",
+    after_throwing = "after_throwing_fn"
+)]
+pub fn try_parse(ok: bool) -> Result<i64, String> {
+    if ok {
+        Ok(42)
+    } else {
+        Err("boom".to_string())
+    }
+}
+
+#[test]
+fn test_aspect_result() {
+    assert_eq!(Ok(42), try_parse(true));
+    assert_eq!(Err("boom".to_string()), try_parse(false));
+}
+
+// ---------------------------------------------------------------- attrs & generics
+
+// The `#[inline]` attribute is forwarded onto the generated function, and the
+// generic parameters together with the `where` clause are re-emitted.
+#[Aspect(before = "before_fn_empty", after = "after_fn_empty")]
+#[inline]
+pub fn default_of<T>() -> T
+where
+    T: Default + std::fmt::Debug,
+{
+    T::default()
+}
+
+#[test]
+fn test_aspect_generic_with_attrs() {
+    assert_eq!(0_i64, default_of::<i64>());
+    assert_eq!(String::new(), default_of::<String>());
+}